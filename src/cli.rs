@@ -29,8 +29,16 @@ pub struct Args {
     #[clap(short = 'd', env = "UMUWRAPPER_CONFIG_DIR_PATH", default_value_t = config_dir_path())]
     pub config_dir: String,
 
+    /// The profile to launch. Required for `run`, ignored by `list`/`validate`
     #[clap(short = 'p', long)]
-    pub profile: String,
+    pub profile: Option<String>,
+
+    /// The edition of the profile to launch, for profiles declaring multiple editions
+    ///
+    /// Defaults to the first/only edition if not set.
+    #[clap(short = 'e', long)]
+    pub edition: Option<String>,
+
     #[clap(subcommand)]
     pub subcommand: Option<Subcommand>,
 }
@@ -39,6 +47,18 @@ pub struct Args {
 pub enum Subcommand {
     #[clap(name = "run")]
     Run { args: Vec<String> },
+    /// Check whether one or all profiles are ready to launch, without launching them
+    #[clap(name = "doctor")]
+    Doctor {
+        /// The profile to check. If unset, all profiles are checked
+        profile: Option<String>,
+    },
+    /// Print all resolved templates and profiles, with their effective proton/prefix/store
+    #[clap(name = "list")]
+    List,
+    /// Resolve every profile and report any missing-template/unresolved-proton errors
+    #[clap(name = "validate")]
+    Validate,
 }
 
 impl Args {
@@ -54,10 +74,12 @@ impl Args {
 
         match &self.subcommand {
             Some(Subcommand::Run { args }) => {
-                info!("Attempting to resolve profile: {}", self.profile);
+                let profile_name = self.profile.as_ref().expect("-p/--profile is required for run");
+
+                info!("Attempting to resolve profile: {}", profile_name);
 
                 let mut profile = config
-                    .resolve_profile(&self.profile)
+                    .resolve_profile(profile_name, self.edition.as_deref())
                     .expect("Failed to resolve profile");
 
                 if !args.is_empty() {
@@ -71,6 +93,76 @@ impl Args {
 
                 let _ = profile.run_profile();
             }
+            Some(Subcommand::Doctor { profile }) => {
+                let names: Vec<String> = match profile {
+                    Some(name) => vec![name.clone()],
+                    None => config.profile.iter().map(|p| p.name.clone()).collect(),
+                };
+
+                for name in names {
+                    match config.resolve_profile(&name, self.edition.as_deref()) {
+                        Ok(resolved) => {
+                            println!("{}: {}", resolved.name, resolved.check_state());
+                        }
+                        Err(e) => {
+                            println!("{}: failed to resolve profile: {}", name, e);
+                        }
+                    }
+                }
+            }
+            Some(Subcommand::List) => {
+                println!("Templates:");
+                for tmpl in &config.template {
+                    match config.resolve_template(&tmpl.name) {
+                        Ok(resolved) => println!(
+                            "  {}: proton={:?} prefix={:?} store={:?}",
+                            resolved.name, resolved.proton, resolved.prefix, resolved.store
+                        ),
+                        Err(e) => println!("  {}: failed to resolve: {}", tmpl.name, e),
+                    }
+                }
+
+                println!("Profiles:");
+                for prof in &config.profile {
+                    match config.resolve_profile(&prof.name, None) {
+                        Ok(resolved) => println!(
+                            "  {}: proton={:?} prefix={:?} store={:?}",
+                            resolved.name, resolved.proton, resolved.prefix, resolved.store
+                        ),
+                        Err(e) => println!("  {}: failed to resolve: {}", prof.name, e),
+                    }
+                }
+            }
+            Some(Subcommand::Validate) => {
+                use crate::state::LauncherState;
+
+                let mut all_ok = true;
+
+                for prof in &config.profile {
+                    match config.resolve_profile(&prof.name, None) {
+                        // a missing prefix isn't a validation failure: umu-run creates it
+                        // on first launch, same as `run_profile`'s own leniency
+                        Ok(resolved) => match resolved.check_state() {
+                            LauncherState::Ready | LauncherState::PrefixMissing(_) => {
+                                println!("{}: OK", resolved.name)
+                            }
+                            state => {
+                                all_ok = false;
+                                println!("{}: {}", resolved.name, state);
+                            }
+                        },
+                        Err(e) => {
+                            all_ok = false;
+                            println!("{}: {}", prof.name, e);
+                        }
+                    }
+                }
+
+                if !all_ok {
+                    error!("Validation failed");
+                    std::process::exit(1);
+                }
+            }
             None => {
                 error!("No subcommand provided");
             }
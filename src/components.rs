@@ -0,0 +1,85 @@
+//! Wine prefix component provisioning (DXVK, corefonts, mfc140, vcrun verbs, ...),
+//! so users don't have to manually run winetricks against each prefix.
+use std::collections::HashSet;
+use std::path::Path;
+
+use tracing::{info, instrument};
+
+use crate::error::Error;
+
+const MARKER_FILE: &str = ".umu-wrapper-components";
+
+fn marker_path(prefix: &Path) -> std::path::PathBuf {
+    prefix.join(MARKER_FILE)
+}
+
+fn installed_components(prefix: &Path) -> HashSet<String> {
+    std::fs::read_to_string(marker_path(prefix))
+        .map(|contents| contents.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn mark_installed(prefix: &Path, component: &str) -> Result<(), Error> {
+    use std::io::Write;
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(marker_path(prefix))?;
+    writeln!(file, "{}", component)?;
+
+    Ok(())
+}
+
+/// Run the winetricks verb for `component` against `prefix` via `umu-run`
+#[instrument]
+fn install_component(
+    prefix: &Path,
+    proton: &Path,
+    game_id: &str,
+    component: &str,
+) -> Result<(), Error> {
+    info!("Installing component {} into {}", component, prefix.display());
+
+    let status = std::process::Command::new("umu-run")
+        .env("GAMEID", game_id)
+        .env("WINEPREFIX", prefix)
+        .env("PROTONPATH", proton)
+        .arg("winetricks")
+        .arg(component)
+        .status()?;
+
+    if !status.success() {
+        return Err(color_eyre::eyre::eyre!(
+            "Failed to install component {} (exit code {:?})",
+            component,
+            status.code()
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Ensure every component in `components` is installed into `prefix`, skipping
+/// any already recorded in the prefix's marker file
+#[instrument]
+pub fn ensure_components(
+    prefix: &Path,
+    proton: &Path,
+    game_id: &str,
+    components: &[String],
+) -> Result<(), Error> {
+    let installed = installed_components(prefix);
+
+    for component in components {
+        if installed.contains(component) {
+            continue;
+        }
+
+        install_component(prefix, proton, game_id, component)?;
+        mark_installed(prefix, component)?;
+    }
+
+    Ok(())
+}
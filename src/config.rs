@@ -1,9 +1,15 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use tracing::{instrument, trace, warn};
+use tracing::{info, instrument, trace, warn};
 
 pub fn generate_prefix_dir(game_id: &str) -> String {
-    format!("~/Games/umu/{}", game_id)
+    dirs::home_dir()
+        .expect("Failed to get home directory")
+        .join("Games")
+        .join("umu")
+        .join(game_id)
+        .to_string_lossy()
+        .to_string()
 }
 
 pub fn bool_to_umu_bool(b: bool) -> String {
@@ -14,6 +20,56 @@ pub fn bool_to_umu_bool(b: bool) -> String {
     }
 }
 
+/// Common Linux gaming tweaks that can be layered onto `Global`, `Template` and `Profile`
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Enhancements {
+    /// Overlay FPS/frametime stats via MangoHud
+    #[serde(default)]
+    pub mangohud: Option<bool>,
+    /// Wrap the launch command in `gamemoderun`
+    #[serde(default)]
+    pub gamemode: Option<bool>,
+    /// Enable WINE's fsync implementation
+    #[serde(default)]
+    pub fsync: Option<bool>,
+    /// Enable WINE's esync implementation
+    #[serde(default)]
+    pub esync: Option<bool>,
+    /// Cap the game's framerate via DXVK
+    #[serde(default)]
+    pub fps_limit: Option<u32>,
+}
+
+impl Enhancements {
+    /// Fill in any unset fields from `other`, preferring `self`'s values
+    fn merge(&self, other: &Enhancements) -> Enhancements {
+        Enhancements {
+            mangohud: self.mangohud.or(other.mangohud),
+            gamemode: self.gamemode.or(other.gamemode),
+            fsync: self.fsync.or(other.fsync),
+            esync: self.esync.or(other.esync),
+            fps_limit: self.fps_limit.or(other.fps_limit),
+        }
+    }
+}
+
+/// Discord Rich Presence configuration, published for the duration of a launched profile
+#[cfg(feature = "discord-rpc")]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DiscordRpc {
+    /// The Discord application ID to publish the presence under
+    pub app_id: String,
+    /// Template for the activity's `state` line. Supports `{name}` and `{game_id}`
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Template for the activity's `details` line. Supports `{name}` and `{game_id}`
+    #[serde(default)]
+    pub details: Option<String>,
+    /// Large image asset key to show on the presence
+    #[serde(default)]
+    pub large_image: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Global {
     #[serde(default)]
@@ -36,6 +92,20 @@ pub struct Global {
     /// The default template to use, if not set in the profile
     #[serde(default)]
     pub default_template: Option<String>,
+
+    /// Whether to automatically download the latest GE-Proton build
+    /// when no `proton` path can be resolved for a profile
+    #[serde(default)]
+    pub auto_proton: bool,
+
+    /// Fallback gameplay enhancement tweaks to apply
+    #[serde(default)]
+    pub enhancements: Enhancements,
+
+    /// Discord Rich Presence settings, published while a profile is running
+    #[cfg(feature = "discord-rpc")]
+    #[serde(default)]
+    pub discord_rpc: Option<DiscordRpc>,
 }
 
 fn default_game_id() -> String {
@@ -101,6 +171,52 @@ pub struct Profile {
     /// This is for native Linux games that require the Steam Linux Runtime
     #[serde(default)]
     pub no_proton: Option<bool>,
+
+    /// Whether to automatically download the latest GE-Proton build
+    /// when no `proton` path can be resolved for this profile
+    #[serde(default)]
+    pub auto_proton: Option<bool>,
+
+    /// Gameplay enhancement tweaks to apply to this profile
+    #[serde(default)]
+    pub enhancements: Enhancements,
+
+    /// Discord Rich Presence settings, published while this profile is running
+    #[cfg(feature = "discord-rpc")]
+    #[serde(default)]
+    pub discord_rpc: Option<DiscordRpc>,
+
+    /// Winetricks components (e.g. `dxvk`, `corefonts`, `mfc140`, `vcrun2022`) to
+    /// provision into the resolved WINEPREFIX before launching
+    #[serde(default)]
+    pub components: Option<Vec<String>>,
+
+    /// Alternate editions of this game (e.g. global vs. regional clients) that
+    /// override a subset of this profile's fields. Selected via `--edition`
+    #[serde(default)]
+    pub editions: Vec<Edition>,
+}
+
+/// A named variant of a [`Profile`], overriding a subset of its fields
+///
+/// Lets one logical profile cover a game that ships distinct client variants,
+/// instead of duplicating the whole profile per variant.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Edition {
+    /// Name of the edition, selected via `-e/--edition`
+    pub name: String,
+    /// Overrides the profile's `game_id` for this edition
+    #[serde(default)]
+    pub game_id: Option<String>,
+    /// Overrides the profile's `store` for this edition
+    #[serde(default)]
+    pub store: Option<String>,
+    /// Overrides the profile's `exe` for this edition
+    #[serde(default)]
+    pub exe: Option<PathBuf>,
+    /// Overrides the profile's `prefix` for this edition
+    #[serde(default)]
+    pub prefix: Option<String>,
 }
 
 impl Profile {
@@ -118,8 +234,61 @@ impl Profile {
     //     (first, rest)
     // }
 
+    /// Validate that this (resolved) profile is ready to be launched
+    #[instrument]
+    pub fn check_state(&self) -> crate::state::LauncherState {
+        use crate::state::LauncherState;
+
+        if !self.no_proton.unwrap_or(false) {
+            match &self.proton {
+                Some(path) if !path.exists() => return LauncherState::ProtonNotFound(path.clone()),
+                None if !self.auto_proton.unwrap_or(false) => {
+                    return LauncherState::ProtonNotConfigured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(prefix) = &self.prefix {
+            if !std::path::Path::new(prefix).exists() {
+                return LauncherState::PrefixMissing(prefix.clone());
+            }
+        }
+
+        if !self.exe.exists() {
+            return LauncherState::ExeMissing(self.exe.clone());
+        }
+
+        if which::which("umu-run").is_err() {
+            return LauncherState::UmuRunNotInstalled;
+        }
+
+        LauncherState::Ready
+    }
+
     #[instrument]
     pub fn run_profile(&self) -> Result<(), crate::error::Error> {
+        use crate::state::LauncherState;
+
+        match self.check_state() {
+            LauncherState::Ready => {}
+            // umu-run creates the WINE prefix on first launch, so a missing
+            // prefix is expected and not fatal here (`doctor` still reports it)
+            LauncherState::PrefixMissing(prefix) => {
+                warn!(
+                    "WINE prefix {} does not exist yet, it will be created on launch",
+                    prefix
+                );
+            }
+            state => {
+                return Err(crate::error::Error::Other(color_eyre::eyre::eyre!(
+                    "Profile {} is not ready to launch: {}",
+                    self.name,
+                    state
+                )));
+            }
+        }
+
         let mut envs = vec![("GAMEID", self.game_id.as_ref().unwrap().as_str())];
 
         if let Some(store) = &self.store {
@@ -139,7 +308,13 @@ impl Profile {
             if let Some(proton) = &self.proton {
                 envs.push(("PROTONPATH", proton.to_str().unwrap()));
             } else {
-                return Err(crate::error::Error::NoProton);
+                // check_state() already rejected the case of no proton path
+                // configured and auto_proton off, so this must be auto_proton
+                info!("No proton path configured, attempting automatic Proton acquisition");
+                let proton = crate::proton::ensure_proton()?;
+                let proton: &'static str =
+                    Box::leak(proton.to_string_lossy().into_owned().into_boxed_str());
+                envs.push(("PROTONPATH", proton));
             }
 
             if let Some(prefix) = &self.prefix {
@@ -153,12 +328,75 @@ impl Profile {
             }
         }
 
-        let mut command = std::process::Command::new("umu-run")
+        if self.enhancements.mangohud.unwrap_or(false) {
+            envs.push(("MANGOHUD", "1"));
+        }
+
+        if self.enhancements.esync.unwrap_or(false) {
+            envs.push(("WINEESYNC", "1"));
+        }
+
+        if self.enhancements.fsync.unwrap_or(false) {
+            envs.push(("WINEFSYNC", "1"));
+        }
+
+        let fps_limit_str = self.enhancements.fps_limit.map(|fps| fps.to_string());
+        if let Some(fps_limit_str) = &fps_limit_str {
+            envs.push(("DXVK_FRAME_RATE", fps_limit_str.as_str()));
+        }
+
+        if !no_proton {
+            if let Some(components) = self.components.as_ref().filter(|c| !c.is_empty()) {
+                let proton_path = envs
+                    .iter()
+                    .find(|(key, _)| *key == "PROTONPATH")
+                    .map(|(_, value)| PathBuf::from(value));
+                let prefix_path = envs
+                    .iter()
+                    .find(|(key, _)| *key == "WINEPREFIX")
+                    .map(|(_, value)| PathBuf::from(value));
+
+                if let (Some(proton_path), Some(prefix_path)) = (proton_path, prefix_path) {
+                    let game_id = self.game_id.as_deref().unwrap_or("0");
+                    crate::components::ensure_components(
+                        &prefix_path,
+                        &proton_path,
+                        game_id,
+                        components,
+                    )?;
+                }
+            }
+        }
+
+        let mut command = if self.enhancements.gamemode.unwrap_or(false) {
+            let mut command = std::process::Command::new("gamemoderun");
+            command.arg("umu-run");
+            command
+        } else {
+            std::process::Command::new("umu-run")
+        };
+
+        let mut command = command
             .envs(envs)
             .arg(self.exe.clone())
             .args(self.args.as_ref().unwrap_or(&vec![]))
             .spawn()?;
 
+        #[cfg(feature = "discord-rpc")]
+        let _presence = match &self.discord_rpc {
+            Some(discord_rpc) => {
+                let game_id = self.game_id.clone().unwrap_or_default();
+                match crate::discord::Presence::connect(discord_rpc, &self.name, &game_id) {
+                    Ok(presence) => Some(presence),
+                    Err(e) => {
+                        warn!("Failed to connect Discord Rich Presence: {}", e);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
         command.wait()?;
 
         Ok(())
@@ -209,17 +447,48 @@ impl Config {
     // Load additional configuration files from a directory
     pub fn load_dir(&mut self, path: &str) -> Result<(), crate::error::Error> {
         // if path not exists, return early
-        if !std::path::Path::new(path).exists() {
+        let dir = std::path::Path::new(path);
+        if !dir.exists() {
             warn!("Path {} does not exist, skipping", path);
             return Ok(());
         }
-        let dir = std::fs::read_dir(path)?;
 
-        for entry in dir {
+        self.load_dir_recursive(dir)
+    }
+
+    // Recurse into `dir`, merging in every `*.toml` file found. A file that fails
+    // to read or parse is logged as a warning and skipped, rather than aborting
+    // the whole load.
+    fn load_dir_recursive(&mut self, dir: &std::path::Path) -> Result<(), crate::error::Error> {
+        for entry in std::fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
-            let config = std::fs::read_to_string(&path)?;
-            let config: Config = toml::from_str(&config)?;
+
+            if path.is_dir() {
+                self.load_dir_recursive(&path)?;
+                continue;
+            }
+
+            if path.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+                continue;
+            }
+
+            let contents = match std::fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    warn!("Failed to read {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            let config: Config = match toml::from_str(&contents) {
+                Ok(config) => config,
+                Err(e) => {
+                    warn!("Failed to parse {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
             trace!("Loaded config from {}", path.display());
 
             self.template.extend(config.template);
@@ -246,11 +515,21 @@ impl Config {
         tmpl.proton = tmpl.proton.or_else(|| self.global.proton.clone());
         tmpl.proton_verb = tmpl.proton_verb.or_else(|| self.global.proton_verb.clone());
         tmpl.store = tmpl.store.or_else(|| self.global.store.clone());
+        tmpl.auto_proton = tmpl.auto_proton.or(Some(self.global.auto_proton));
+        tmpl.enhancements = tmpl.enhancements.merge(&self.global.enhancements);
+        #[cfg(feature = "discord-rpc")]
+        {
+            tmpl.discord_rpc = tmpl.discord_rpc.clone().or_else(|| self.global.discord_rpc.clone());
+        }
 
         Ok(tmpl)
     }
     #[instrument]
-    pub fn resolve_profile(&self, name: &str) -> Result<Profile, crate::error::Error> {
+    pub fn resolve_profile(
+        &self,
+        name: &str,
+        edition: Option<&str>,
+    ) -> Result<Profile, crate::error::Error> {
         let prof = self
             .profile
             .iter()
@@ -271,12 +550,48 @@ impl Config {
 
         let mut prof = prof.clone();
 
+        // apply the selected edition's overrides, taking top precedence over the base profile
+        if !prof.editions.is_empty() {
+            let edition_name = edition.unwrap_or(prof.editions[0].name.as_str());
+            let selected = prof
+                .editions
+                .iter()
+                .find(|e| e.name == edition_name)
+                .ok_or_else(|| {
+                    color_eyre::eyre::eyre!(
+                        "Edition {} not found in profile {}",
+                        edition_name,
+                        prof.name
+                    )
+                })?
+                .clone();
+
+            prof.game_id = selected.game_id.or(prof.game_id);
+            prof.store = selected.store.or(prof.store);
+            prof.exe = selected.exe.unwrap_or(prof.exe);
+            prof.prefix = selected.prefix.or(prof.prefix);
+        } else if let Some(edition_name) = edition {
+            return Err(color_eyre::eyre::eyre!(
+                "Profile {} has no editions defined, but --edition {} was given",
+                prof.name,
+                edition_name
+            )
+            .into());
+        }
+
         // populate values from template
         prof.proton = prof.proton.or_else(|| tmpl.proton.clone());
         prof.proton_verb = prof.proton_verb.or_else(|| tmpl.proton_verb.clone());
         prof.store = prof.store.or_else(|| tmpl.store.clone());
         prof.game_id = prof.game_id.or_else(|| Some(self.global.game_id.clone()));
         prof.no_proton = prof.no_proton.or(tmpl.no_proton);
+        prof.auto_proton = prof.auto_proton.or(tmpl.auto_proton);
+        prof.enhancements = prof.enhancements.merge(&tmpl.enhancements);
+        #[cfg(feature = "discord-rpc")]
+        {
+            prof.discord_rpc = prof.discord_rpc.clone().or_else(|| tmpl.discord_rpc.clone());
+        }
+        prof.components = prof.components.or_else(|| tmpl.components.clone());
         prof.prefix = prof.prefix.or_else(|| tmpl.prefix.clone()).or_else(|| {
             Some(generate_prefix_dir(
                 prof.game_id.as_ref().unwrap_or(&self.global.game_id),
@@ -330,4 +645,106 @@ pub struct Template {
     /// This is for native Linux games that require the Steam Linux Runtime
     #[serde(default)]
     pub no_proton: Option<bool>,
+
+    /// Whether to automatically download the latest GE-Proton build
+    /// when no `proton` path can be resolved for this template
+    #[serde(default)]
+    pub auto_proton: Option<bool>,
+
+    /// Gameplay enhancement tweaks to apply to profiles using this template
+    #[serde(default)]
+    pub enhancements: Enhancements,
+
+    /// Discord Rich Presence settings, published while profiles using this template are running
+    #[cfg(feature = "discord-rpc")]
+    #[serde(default)]
+    pub discord_rpc: Option<DiscordRpc>,
+
+    /// Winetricks components (e.g. `dxvk`, `corefonts`, `mfc140`, `vcrun2022`) to
+    /// provision into the resolved WINEPREFIX before launching
+    #[serde(default)]
+    pub components: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enhancements_merge_prefers_self_over_other() {
+        let specific = Enhancements {
+            mangohud: Some(true),
+            ..Default::default()
+        };
+        let fallback = Enhancements {
+            mangohud: Some(false),
+            gamemode: Some(true),
+            fps_limit: Some(60),
+            ..Default::default()
+        };
+
+        let merged = specific.merge(&fallback);
+
+        assert_eq!(merged.mangohud, Some(true));
+        assert_eq!(merged.gamemode, Some(true));
+        assert_eq!(merged.fps_limit, Some(60));
+    }
+}
+
+#[cfg(test)]
+mod edition_tests {
+    use super::*;
+
+    fn config_with_editions() -> Config {
+        toml::from_str(
+            r#"
+            [global]
+            default_template = "base"
+
+            [[template]]
+            name = "base"
+            prefix = "/prefix/template"
+
+            [[profile]]
+            name = "game"
+            exe = "/game/default.exe"
+
+            [[profile.editions]]
+            name = "global"
+            exe = "/game/global.exe"
+
+            [[profile.editions]]
+            name = "cn"
+            exe = "/game/cn.exe"
+            prefix = "/prefix/cn"
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn resolve_profile_applies_selected_edition_over_base_and_template() {
+        let config = config_with_editions();
+
+        let resolved = config.resolve_profile("game", Some("cn")).unwrap();
+        assert_eq!(resolved.exe, PathBuf::from("/game/cn.exe"));
+        assert_eq!(resolved.prefix.as_deref(), Some("/prefix/cn"));
+    }
+
+    #[test]
+    fn resolve_profile_defaults_to_first_edition_when_unspecified() {
+        let config = config_with_editions();
+
+        let resolved = config.resolve_profile("game", None).unwrap();
+        assert_eq!(resolved.exe, PathBuf::from("/game/global.exe"));
+        // the "global" edition doesn't override prefix, so it falls through to the template
+        assert_eq!(resolved.prefix.as_deref(), Some("/prefix/template"));
+    }
+
+    #[test]
+    fn resolve_profile_rejects_unknown_edition() {
+        let config = config_with_editions();
+
+        assert!(config.resolve_profile("game", Some("jp")).is_err());
+    }
 }
@@ -0,0 +1,68 @@
+//! Discord Rich Presence, active for the lifetime of a running profile.
+//!
+//! Gated behind the `discord-rpc` feature, following anime-launcher-sdk's approach.
+use discord_rich_presence::{activity, DiscordIpc, DiscordIpcClient};
+
+use crate::config::DiscordRpc;
+use crate::error::Error;
+
+/// A connected Discord IPC session. Dropping this clears the activity and disconnects.
+pub struct Presence {
+    client: DiscordIpcClient,
+}
+
+impl Presence {
+    /// Connect to the local Discord client and publish an activity for `profile_name`
+    pub fn connect(config: &DiscordRpc, profile_name: &str, game_id: &str) -> Result<Self, Error> {
+        let mut client = DiscordIpcClient::new(&config.app_id)
+            .map_err(|e| Error::Other(color_eyre::eyre::eyre!(e)))?;
+        client
+            .connect()
+            .map_err(|e| Error::Other(color_eyre::eyre::eyre!(e)))?;
+
+        let start_timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+
+        let details = interpolate(
+            config.details.as_deref().unwrap_or("Playing {name}"),
+            profile_name,
+            game_id,
+        );
+        let title = interpolate(
+            config.title.as_deref().unwrap_or("{name}"),
+            profile_name,
+            game_id,
+        );
+
+        let mut assets = activity::Assets::new();
+        if let Some(large_image) = &config.large_image {
+            assets = assets.large_image(large_image);
+        }
+
+        let activity = activity::Activity::new()
+            .details(&details)
+            .state(&title)
+            .assets(assets)
+            .timestamps(activity::Timestamps::new().start(start_timestamp));
+
+        client
+            .set_activity(activity)
+            .map_err(|e| Error::Other(color_eyre::eyre::eyre!(e)))?;
+
+        Ok(Self { client })
+    }
+}
+
+impl Drop for Presence {
+    fn drop(&mut self) {
+        let _ = self.client.clear_activity();
+        let _ = self.client.close();
+    }
+}
+
+/// Interpolate `{name}` and `{game_id}` placeholders in a title/details template
+fn interpolate(template: &str, name: &str, game_id: &str) -> String {
+    template.replace("{name}", name).replace("{game_id}", game_id)
+}
@@ -1,8 +1,8 @@
 use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("No Proton installation provided")]
-    NoProton,
+    #[error("Checksum mismatch while verifying downloaded Proton build")]
+    ChecksumMismatch,
 
     #[error("IO Error: {0}")]
     Io(#[from] std::io::Error),
@@ -10,6 +10,9 @@ pub enum Error {
     #[error("Serde Error: {0}")]
     Serde(#[from] toml::de::Error),
 
+    #[error("HTTP Error: {0}")]
+    Http(#[from] ureq::Error),
+
     #[error("{0}")]
     Other(#[from] color_eyre::eyre::Report),
 }
@@ -3,8 +3,13 @@ use color_eyre::Result;
 use tracing_subscriber::EnvFilter;
 
 mod cli;
+mod components;
 mod config;
+#[cfg(feature = "discord-rpc")]
+mod discord;
 mod error;
+mod proton;
+mod state;
 
 
 
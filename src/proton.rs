@@ -0,0 +1,212 @@
+//! Automatic acquisition of GE-Proton builds, mirroring umu-launcher's own downloader.
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use sha2::{Digest, Sha512};
+use tracing::{info, instrument, trace};
+
+use crate::error::Error;
+
+const RELEASES_URL: &str =
+    "https://api.github.com/repos/GloriousEggroll/proton-ge-custom/releases/latest";
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Asset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// Directory that downloaded Proton builds are cached in
+pub fn cache_dir() -> PathBuf {
+    dirs::data_dir()
+        .expect("Failed to get data directory")
+        .join("umu-wrapper")
+        .join("compatibilitytools")
+}
+
+/// File recording the tag of the last successfully resolved Proton build,
+/// so repeated launches can skip the GitHub API call entirely
+fn version_file(cache_dir: &Path) -> PathBuf {
+    cache_dir.join(".version")
+}
+
+fn cached_version_dir(cache_dir: &Path) -> Option<PathBuf> {
+    let tag = std::fs::read_to_string(version_file(cache_dir)).ok()?;
+    let tag = tag.trim();
+    let version_dir = cache_dir.join(tag);
+
+    version_dir.exists().then_some(version_dir)
+}
+
+fn fetch_latest_release() -> Result<Release, Error> {
+    let release: Release = ureq::get(RELEASES_URL)
+        .set("User-Agent", "umu-wrapper")
+        .call()?
+        .into_json()
+        .map_err(|e| Error::Other(color_eyre::eyre::eyre!(e)))?;
+
+    Ok(release)
+}
+
+fn download_bytes(url: &str) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+    ureq::get(url)
+        .call()?
+        .into_reader()
+        .read_to_end(&mut bytes)?;
+
+    Ok(bytes)
+}
+
+fn verify_checksum(data: &[u8], sha512sum: &str) -> Result<(), Error> {
+    let expected = sha512sum
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| color_eyre::eyre::eyre!("Malformed .sha512sum file"))?;
+
+    let mut hasher = Sha512::new();
+    hasher.update(data);
+    let actual = hex::encode(hasher.finalize());
+
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Err(Error::ChecksumMismatch);
+    }
+
+    Ok(())
+}
+
+fn extract_tarball(data: &[u8], dest: &Path) -> Result<(), Error> {
+    let decoder = flate2::read::GzDecoder::new(data);
+    let mut archive = tar::Archive::new(decoder);
+    archive.unpack(dest)?;
+
+    Ok(())
+}
+
+/// Resolve a usable Proton installation, downloading the latest GE-Proton build
+/// into [`cache_dir`] if one isn't already cached.
+///
+/// Returns the path to the extracted Proton directory, suitable for `PROTONPATH`.
+/// Repeated calls skip the GitHub API/download entirely as long as the cached
+/// build recorded in the version file is still present on disk.
+#[instrument]
+pub fn ensure_proton() -> Result<PathBuf, Error> {
+    let cache_dir = cache_dir();
+    std::fs::create_dir_all(&cache_dir)?;
+
+    if let Some(version_dir) = cached_version_dir(&cache_dir) {
+        trace!("Using cached Proton build at {}", version_dir.display());
+        return Ok(version_dir);
+    }
+
+    let release = fetch_latest_release()?;
+    let version_dir = cache_dir.join(&release.tag_name);
+
+    let tarball = release
+        .assets
+        .iter()
+        .find(|a| a.name.ends_with(".tar.gz"))
+        .ok_or_else(|| color_eyre::eyre::eyre!("No .tar.gz asset found in latest release"))?;
+
+    let checksum_asset = release
+        .assets
+        .iter()
+        .find(|a| a.name.ends_with(".sha512sum"))
+        .ok_or_else(|| color_eyre::eyre::eyre!("No .sha512sum asset found in latest release"))?;
+
+    info!("Downloading {} ({})", tarball.name, release.tag_name);
+    let archive_bytes = download_bytes(&tarball.browser_download_url)?;
+    let checksum_bytes = download_bytes(&checksum_asset.browser_download_url)?;
+    let checksum_text = String::from_utf8_lossy(&checksum_bytes);
+
+    verify_checksum(&archive_bytes, &checksum_text)?;
+    // GE-Proton tarballs already contain a top-level directory named after the
+    // release tag, so extract into cache_dir directly rather than version_dir
+    // to avoid nesting it one level too deep.
+    extract_tarball(&archive_bytes, &cache_dir)?;
+
+    if !version_dir.is_dir() {
+        return Err(color_eyre::eyre::eyre!(
+            "Extraction reported success but {} was not created",
+            version_dir.display()
+        )
+        .into());
+    }
+
+    std::fs::write(version_file(&cache_dir), &release.tag_name)?;
+
+    Ok(version_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_checksum_accepts_matching_hash_regardless_of_case() {
+        let data = b"some proton tarball bytes";
+        let mut hasher = Sha512::new();
+        hasher.update(data);
+        let digest = hex::encode(hasher.finalize());
+
+        let sha512sum = format!("{}  GE-Proton.tar.gz\n", digest.to_uppercase());
+
+        assert!(verify_checksum(data, &sha512sum).is_ok());
+    }
+
+    #[test]
+    fn verify_checksum_rejects_mismatched_hash() {
+        let sha512sum = format!("{}  GE-Proton.tar.gz\n", "0".repeat(128));
+
+        assert!(matches!(
+            verify_checksum(b"some proton tarball bytes", &sha512sum),
+            Err(Error::ChecksumMismatch)
+        ));
+    }
+
+    #[test]
+    fn verify_checksum_rejects_empty_sha512sum_file() {
+        assert!(verify_checksum(b"data", "").is_err());
+    }
+
+    #[test]
+    fn cached_version_dir_is_none_without_a_version_file() {
+        let tmp = tempdir();
+        assert_eq!(cached_version_dir(&tmp), None);
+    }
+
+    #[test]
+    fn cached_version_dir_is_none_when_the_recorded_tag_was_removed() {
+        let tmp = tempdir();
+        std::fs::write(version_file(&tmp), "GE-Proton9-20").unwrap();
+
+        assert_eq!(cached_version_dir(&tmp), None);
+    }
+
+    #[test]
+    fn cached_version_dir_resolves_when_the_recorded_tag_is_present() {
+        let tmp = tempdir();
+        let version_dir = tmp.join("GE-Proton9-20");
+        std::fs::create_dir_all(&version_dir).unwrap();
+        std::fs::write(version_file(&tmp), "GE-Proton9-20\n").unwrap();
+
+        assert_eq!(cached_version_dir(&tmp), Some(version_dir));
+    }
+
+    fn tempdir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "umu-wrapper-proton-test-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+}
@@ -0,0 +1,42 @@
+//! Pre-launch validation, so misconfigured profiles fail with a clear reason
+//! instead of `umu-run` being spawned against a broken setup.
+use std::fmt;
+use std::path::PathBuf;
+
+/// The result of validating a resolved [`crate::config::Profile`] before launch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LauncherState {
+    /// Everything needed to launch the profile is in place
+    Ready,
+    /// No `proton` path was configured at all, and `auto_proton` is off
+    ProtonNotConfigured,
+    /// A `proton` path was configured, but nothing exists there
+    ProtonNotFound(PathBuf),
+    /// The WINE prefix directory does not exist yet
+    PrefixMissing(String),
+    /// The game executable does not exist at the given path
+    ExeMissing(PathBuf),
+    /// `umu-run` could not be found on `$PATH`
+    UmuRunNotInstalled,
+}
+
+impl fmt::Display for LauncherState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LauncherState::Ready => write!(f, "Ready"),
+            LauncherState::ProtonNotConfigured => {
+                write!(f, "No Proton path configured and auto_proton is disabled")
+            }
+            LauncherState::ProtonNotFound(path) => {
+                write!(f, "Proton installation not found at {}", path.display())
+            }
+            LauncherState::PrefixMissing(prefix) => {
+                write!(f, "WINE prefix does not exist: {}", prefix)
+            }
+            LauncherState::ExeMissing(exe) => {
+                write!(f, "Game executable does not exist: {}", exe.display())
+            }
+            LauncherState::UmuRunNotInstalled => write!(f, "umu-run was not found on $PATH"),
+        }
+    }
+}